@@ -7,14 +7,32 @@ use hex;
 use std::fs;
 use std::path::{PathBuf};
 use subtle::ConstantTimeEq;
+// NOTE: ed25519-dalek is a new hard dependency; no Cargo.toml exists in this
+// repo yet to declare it (or any other crate this file uses). Whoever owns
+// the manifest needs to add it alongside hmac/sha2/subtle/hex/chrono/uuid/rand.
+use ed25519_dalek::{Signer as Ed25519Signer, SigningKey, Verifier, VerifyingKey};
 
 type HmacSha256 = Hmac<Sha256>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyType {
+    #[default]
+    HmacSha256,
+    Ed25519,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AeonDIDDocument {
     pub did: String,
     pub name: String,
     pub signing_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<KeyType>,
+    /// Public key for the `ed25519` key type; absent for `hmac-sha256` documents
+    /// since the HMAC mode has no publishable counterpart to the shared secret.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
@@ -59,14 +77,19 @@ impl Signer {
         Ok(Signature { timestamp, nonce, hash })
     }
 
-    pub fn verify(signing_key: &str, signature: &Signature, message: &serde_json::Value, max_age_ms: u64) -> bool {
-        let signed_at = match signature.timestamp.parse::<i64>() {
-            Ok(t) => t,
-            Err(_) => return false,
-        };
-        let now = Utc::now().timestamp_millis();
+    /// Unified verification entry point that dispatches on `key_type` so callers
+    /// holding an `AeonDIDDocument` don't need to branch between HMAC and Ed25519
+    /// themselves. `key` is the shared `signing_key` for `HmacSha256` or the
+    /// hex-encoded public key for `Ed25519`.
+    pub fn verify(key_type: KeyType, key: &str, signature: &Signature, message: &serde_json::Value, max_age_ms: u64) -> bool {
+        match key_type {
+            KeyType::HmacSha256 => Self::verify_hmac(key, signature, message, max_age_ms),
+            KeyType::Ed25519 => Self::verify_ed25519(key, signature, message, max_age_ms),
+        }
+    }
 
-        if (now - signed_at).abs() as u64 > max_age_ms {
+    fn verify_hmac(signing_key: &str, signature: &Signature, message: &serde_json::Value, max_age_ms: u64) -> bool {
+        if !Self::within_max_age(&signature.timestamp, max_age_ms) {
             return false;
         }
 
@@ -92,7 +115,89 @@ impl Signer {
         sig_hash_bytes.ct_eq(&expected_hash_bytes).into()
     }
 
-    fn stable_stringify(message: &serde_json::Value) -> Result<String, Box<dyn std::error::Error>> {
+    /// Generates an Ed25519 keypair for identity-based (rather than shared-secret)
+    /// signing, returned as `(secret_hex, public_hex)`.
+    pub fn generate_keypair() -> (String, String) {
+        let mut seed = [0u8; 32];
+        rand::Rng::fill(&mut rand::thread_rng(), &mut seed);
+        let signing_key = SigningKey::from_bytes(&seed);
+        let public_key = signing_key.verifying_key();
+        (hex::encode(signing_key.to_bytes()), hex::encode(public_key.to_bytes()))
+    }
+
+    /// Ed25519 counterpart to [`Signer::sign`]: builds the same
+    /// `timestamp:nonce:stable_stringify(message)` payload so the two modes stay
+    /// cross-language compatible, then produces a 64-byte detached signature.
+    pub fn sign_ed25519(secret: &str, message: &serde_json::Value, timestamp: Option<String>, nonce: Option<String>) -> Result<Signature, Box<dyn std::error::Error>> {
+        let timestamp = timestamp.unwrap_or_else(|| Utc::now().timestamp_millis().to_string());
+        let nonce = nonce.unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        let message_str = Self::stable_stringify(message)?;
+        let payload = format!("{}:{}:{}", timestamp, nonce, message_str);
+
+        let secret_bytes = hex::decode(secret)?;
+        let secret_bytes: [u8; 32] = secret_bytes.as_slice().try_into().map_err(|_| "Invalid secret key length")?;
+        let signing_key = SigningKey::from_bytes(&secret_bytes);
+        let signature = signing_key.sign(payload.as_bytes());
+
+        Ok(Signature {
+            timestamp,
+            nonce,
+            hash: hex::encode(signature.to_bytes()),
+        })
+    }
+
+    pub fn verify_ed25519(public_key: &str, signature: &Signature, message: &serde_json::Value, max_age_ms: u64) -> bool {
+        if !Self::within_max_age(&signature.timestamp, max_age_ms) {
+            return false;
+        }
+
+        let public_bytes = match hex::decode(public_key) {
+            Ok(b) => b,
+            Err(_) => return false,
+        };
+        let public_bytes: [u8; 32] = match public_bytes.as_slice().try_into() {
+            Ok(b) => b,
+            Err(_) => return false,
+        };
+        let verifying_key = match VerifyingKey::from_bytes(&public_bytes) {
+            Ok(k) => k,
+            Err(_) => return false,
+        };
+
+        let sig_bytes = match hex::decode(&signature.hash) {
+            Ok(b) => b,
+            Err(_) => return false,
+        };
+        let sig_bytes: [u8; 64] = match sig_bytes.as_slice().try_into() {
+            Ok(b) => b,
+            Err(_) => return false,
+        };
+        let ed_signature = ed25519_dalek::Signature::from_bytes(&sig_bytes);
+
+        let message_str = match Self::stable_stringify(message) {
+            Ok(s) => s,
+            Err(_) => return false,
+        };
+        let payload = format!("{}:{}:{}", signature.timestamp, signature.nonce, message_str);
+
+        verifying_key.verify(payload.as_bytes(), &ed_signature).is_ok()
+    }
+
+    fn within_max_age(timestamp: &str, max_age_ms: u64) -> bool {
+        let signed_at = match timestamp.parse::<i64>() {
+            Ok(t) => t,
+            Err(_) => return false,
+        };
+        let now = Utc::now().timestamp_millis();
+        (now - signed_at).abs() as u64 <= max_age_ms
+    }
+
+    /// Canonical JSON stringification used by every signing mode so the same
+    /// payload hashes identically across this SDK and the Python/TS ones.
+    /// `pub(crate)` so other subsystems (e.g. `protocol`'s hash-linked audit
+    /// chain) can reuse the exact same ordering instead of re-deriving it.
+    pub(crate) fn stable_stringify(message: &serde_json::Value) -> Result<String, Box<dyn std::error::Error>> {
         fn normalize(value: &serde_json::Value) -> serde_json::Value {
             match value {
                 serde_json::Value::Object(map) => {
@@ -119,6 +224,89 @@ impl Signer {
             _ => Ok(serde_json::to_string(&normalized)?),
         }
     }
+
+    /// Like [`Signer::verify`] but also rejects a nonce `guard` has already seen.
+    pub fn verify_with_replay(
+        key_type: KeyType,
+        key: &str,
+        signature: &Signature,
+        message: &serde_json::Value,
+        max_age_ms: u64,
+        agent_did: &str,
+        guard: &mut ReplayGuard,
+    ) -> Result<(), ReplayError> {
+        if !Self::verify(key_type, key, signature, message, max_age_ms) {
+            return Err(ReplayError::InvalidSignature);
+        }
+
+        guard.check_and_record(agent_did, signature, max_age_ms)
+    }
+}
+
+/// Tracks `(agent_did, nonce)` pairs seen within the freshness window used by
+/// [`Signer::verify`].
+#[derive(Debug, Default)]
+pub struct ReplayGuard {
+    /// agent_did -> (nonce -> the signature's claimed timestamp, ms since epoch)
+    seen: std::collections::HashMap<String, std::collections::HashMap<String, i64>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The signature itself failed timestamp/HMAC/Ed25519 verification.
+    InvalidSignature,
+    /// This `(agent_did, nonce)` pair was already recorded within the window.
+    NonceReused,
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::InvalidSignature => write!(f, "signature failed verification"),
+            ReplayError::NonceReused => write!(f, "nonce already seen within the replay window"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl ReplayGuard {
+    pub fn new() -> Self {
+        Self { seen: std::collections::HashMap::new() }
+    }
+
+    /// Rejects `signature.nonce` if it was already recorded for `agent_did`
+    /// within `max_age_ms`, otherwise records it. Clock-skewed timestamps are
+    /// treated the same as `Signer::verify` does: the absolute delta is what's
+    /// checked, not the sign of it.
+    pub fn check_and_record(&mut self, agent_did: &str, signature: &Signature, max_age_ms: u64) -> Result<(), ReplayError> {
+        self.check_and_record_at(agent_did, signature, max_age_ms, Utc::now().timestamp_millis())
+    }
+
+    /// Same as [`ReplayGuard::check_and_record`] but takes the current time
+    /// explicitly, so callers (tests included) can exercise eviction without
+    /// sleeping.
+    pub fn check_and_record_at(&mut self, agent_did: &str, signature: &Signature, max_age_ms: u64, now: i64) -> Result<(), ReplayError> {
+        let signed_at = signature.timestamp.parse::<i64>().unwrap_or(now);
+
+        let nonces = self.seen.entry(agent_did.to_string()).or_default();
+        // Each nonce ages out on its own claimed timestamp, not on whether the
+        // map has been touched recently.
+        nonces.retain(|_, ts| (now - *ts).unsigned_abs() <= max_age_ms);
+
+        let result = if nonces.contains_key(&signature.nonce) {
+            Err(ReplayError::NonceReused)
+        } else {
+            nonces.insert(signature.nonce.clone(), signed_at);
+            Ok(())
+        };
+
+        if self.seen.get(agent_did).is_some_and(|n| n.is_empty()) {
+            self.seen.remove(agent_did);
+        }
+
+        result
+    }
 }
 
 pub struct AeonDID {
@@ -137,6 +325,31 @@ impl AeonDID {
             did: format!("did:aeon:{}", name),
             name: name.to_string(),
             signing_key,
+            key_type: Some(KeyType::HmacSha256),
+            public_key: None,
+            created_at: Utc::now(),
+            metadata,
+        };
+
+        Ok(AeonDID { document })
+    }
+
+    /// Like [`AeonDID::create`] but mints an Ed25519 keypair instead of a shared
+    /// HMAC secret, publishing the public key on the document so governance can
+    /// verify `IntentContext.signature` without holding the agent's secret.
+    pub fn create_ed25519(name: &str, metadata: Option<serde_json::Value>) -> Result<Self, Box<dyn std::error::Error>> {
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+            return Err("Invalid DID name. Use lowercase letters, numbers, and hyphens.".into());
+        }
+
+        let (secret_hex, public_hex) = Signer::generate_keypair();
+
+        let document = AeonDIDDocument {
+            did: format!("did:aeon:{}", name),
+            name: name.to_string(),
+            signing_key: secret_hex,
+            key_type: Some(KeyType::Ed25519),
+            public_key: Some(public_hex),
             created_at: Utc::now(),
             metadata,
         };
@@ -152,3 +365,41 @@ impl AeonDID {
         &self.document.signing_key
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sig(nonce: &str, timestamp: i64) -> Signature {
+        Signature { timestamp: timestamp.to_string(), nonce: nonce.to_string(), hash: "deadbeef".to_string() }
+    }
+
+    #[test]
+    fn rejects_an_immediate_replay() {
+        let mut guard = ReplayGuard::new();
+        assert!(guard.check_and_record_at("did:aeon:agent1", &sig("n1", 1_000), 1_000, 1_000).is_ok());
+        assert_eq!(
+            guard.check_and_record_at("did:aeon:agent1", &sig("n1", 1_000), 1_000, 1_000),
+            Err(ReplayError::NonceReused)
+        );
+    }
+
+    #[test]
+    fn evicts_nonces_individually_by_their_own_age() {
+        let mut guard = ReplayGuard::new();
+        // n1 is recorded early, n2 later; only n1 should have aged out by t=2_200.
+        guard.check_and_record_at("did:aeon:agent1", &sig("n1", 1_000), 1_000, 1_000).unwrap();
+        guard.check_and_record_at("did:aeon:agent1", &sig("n2", 1_500), 1_000, 1_500).unwrap();
+
+        // A third, unrelated nonce triggers eviction as a side effect.
+        assert!(guard.check_and_record_at("did:aeon:agent1", &sig("n3", 2_200), 1_000, 2_200).is_ok());
+
+        // n1 aged out, so replaying it is accepted again.
+        assert!(guard.check_and_record_at("did:aeon:agent1", &sig("n1", 1_000), 1_000, 2_200).is_ok());
+        // n2 is still within the window, so replaying it is rejected.
+        assert_eq!(
+            guard.check_and_record_at("did:aeon:agent1", &sig("n2", 1_500), 1_000, 2_200),
+            Err(ReplayError::NonceReused)
+        );
+    }
+}
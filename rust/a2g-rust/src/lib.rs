@@ -5,7 +5,7 @@
 pub mod did;
 pub mod protocol;
 
-pub use did::{Signer, Signature, AeonDID};
+pub use did::{Signer, Signature, AeonDID, KeyType, ReplayGuard, ReplayError};
 pub use protocol::*;
 
 // Re-export common types
@@ -1,6 +1,16 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use sha2::{Digest, Sha256};
+use hex;
+
+use crate::did::Signer;
+
+pub mod attestation;
+pub use attestation::AttestationEvidence;
+
+pub mod session;
+pub use session::SessionToken;
 
 // =============================================================================
 // A2G MESSAGE TYPES (Agent → Governance)
@@ -36,6 +46,10 @@ pub struct IntentContext {
     /// Signature context required for identity validation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature: Option<super::did::Signature>,
+    /// A previously-issued [`SessionToken`] an agent may present instead of a
+    /// fresh `signature` once registered, to avoid re-signing every intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_token: Option<String>,
 }
 
 impl A2gIntent {
@@ -60,6 +74,7 @@ impl A2gIntent {
             parent_intent: None,
             reasoning: None,
             signature: None,
+            session_token: None,
         });
         ctx.reasoning = Some(reasoning.to_string());
         self.params.context = Some(ctx);
@@ -161,6 +176,10 @@ pub struct RegisterParams {
     pub capabilities_requested: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<AgentMetadata>,
+    /// TEE remote-attestation evidence, required by high-trust deployments
+    /// before `capabilities_requested` is approved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attestation: Option<AttestationEvidence>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -171,6 +190,38 @@ pub struct AgentMetadata {
     pub runtime: Option<String>,
 }
 
+/// A2G_SIMULATE: Dry-run an ordinary intent against a candidate policy instead
+/// of the one currently in force, so operators can diff verdicts before
+/// promoting a draft `G2aPolicy` — the same "draft policy" idea `attestation`
+/// uses, applied to governance itself. Never logged to the `MessageChain` and
+/// never mutates session state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct A2gSimulate {
+    pub jsonrpc: String,
+    pub method: String, // "a2g/simulate"
+    pub params: SimulateParams,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateParams {
+    pub intent: IntentParams,
+    pub draft_policy: PolicyCapabilities,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constitution_hash: Option<String>,
+}
+
+impl A2gSimulate {
+    pub fn new(intent: IntentParams, draft_policy: PolicyCapabilities) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            method: "a2g/simulate".to_string(),
+            params: SimulateParams { intent, draft_policy, constitution_hash: None },
+            id: Uuid::new_v4().to_string(),
+        }
+    }
+}
+
 // =============================================================================
 // G2A MESSAGE TYPES (Governance → Agent)
 // =============================================================================
@@ -197,6 +248,10 @@ pub struct VerdictResult {
     pub conditions: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expires_at: Option<DateTime<Utc>>,
+    /// Minted on approval/register so the agent can skip re-signing subsequent
+    /// intents; see [`session::TokenIssuer`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_token: Option<SessionToken>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -317,3 +372,202 @@ pub struct ResourceLimits {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_disk_mb: Option<u32>,
 }
+
+/// G2A_SIMULATION_RESULT: the full verdict an `A2gSimulate`'s draft policy
+/// *would* produce, had it been in force.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct G2aSimulationResult {
+    pub jsonrpc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<SimulationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<VerdictError>,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub verdict: Verdict,
+    pub intent_id: String,
+    pub risk_assessment: RiskAssessment,
+    /// Which `ToolPolicy`/`NetworkPolicy`/`ResourceLimits` clause of the draft
+    /// policy decided the verdict, e.g. `"tools.shell_exec.allowed=false"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decisive_clause: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constitution_hash: Option<String>,
+    /// Always `false`: a simulation never applies its verdict or mutates
+    /// session state, and is never appended to the `MessageChain`.
+    pub side_effects_applied: bool,
+}
+
+impl SimulationResult {
+    pub fn new(verdict: Verdict, intent_id: &str, risk_assessment: RiskAssessment) -> Self {
+        Self {
+            verdict,
+            intent_id: intent_id.to_string(),
+            risk_assessment,
+            decisive_clause: None,
+            constitution_hash: None,
+            side_effects_applied: false,
+        }
+    }
+}
+
+// =============================================================================
+// MESSAGE CHAIN: hash-linked tamper-evident audit log
+// =============================================================================
+
+/// One logged `A2gIntent`/`A2gReport` event, hash-linked to its predecessor the
+/// way SSB feeds link author/sequence/previous/hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainEntry {
+    pub sequence: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<String>,
+    pub author: String,
+    pub method: String,
+    pub params: serde_json::Value,
+    pub timestamp: DateTime<Utc>,
+    pub hash: String,
+}
+
+impl ChainEntry {
+    /// `hash = hex(sha256(stable_stringify(entry_without_hash)))`, using the
+    /// same canonical ordering `did::Signer` uses for signing payloads.
+    fn compute_hash(sequence: u64, previous: &Option<String>, author: &str, method: &str, params: &serde_json::Value, timestamp: &DateTime<Utc>) -> Result<String, Box<dyn std::error::Error>> {
+        let unhashed = serde_json::json!({
+            "sequence": sequence,
+            "previous": previous,
+            "author": author,
+            "method": method,
+            "params": params,
+            "timestamp": timestamp,
+        });
+        let canonical = Signer::stable_stringify(&unhashed)?;
+        let digest = Sha256::digest(canonical.as_bytes());
+        Ok(hex::encode(digest))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainError {
+    /// `previous`/`hash` didn't match the recomputed hash of the entry at this sequence.
+    HashMismatch { sequence: u64 },
+    /// Sequence numbers must be contiguous starting at 1.
+    SequenceGap { expected: u64, found: u64 },
+}
+
+impl std::fmt::Display for ChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainError::HashMismatch { sequence } => write!(f, "hash mismatch at sequence {}", sequence),
+            ChainError::SequenceGap { expected, found } => write!(f, "expected sequence {}, found {}", expected, found),
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+/// Append-only, hash-linked log of every `A2gIntent`/`A2gReport` an agent has sent.
+#[derive(Debug, Clone, Default)]
+pub struct MessageChain {
+    author: String,
+    entries: Vec<ChainEntry>,
+}
+
+impl MessageChain {
+    pub fn new(author: &str) -> Self {
+        Self { author: author.to_string(), entries: Vec::new() }
+    }
+
+    pub fn entries(&self) -> &[ChainEntry] {
+        &self.entries
+    }
+
+    /// Appends a new entry linking it to the previous one's hash, returning the
+    /// entry that was recorded.
+    pub fn append(&mut self, method: &str, params: serde_json::Value) -> ChainEntry {
+        let sequence = self.entries.last().map(|e| e.sequence + 1).unwrap_or(1);
+        let previous = self.entries.last().map(|e| e.hash.clone());
+        let timestamp = Utc::now();
+
+        let hash = ChainEntry::compute_hash(sequence, &previous, &self.author, method, &params, &timestamp)
+            .expect("stable_stringify of a well-formed params value should not fail");
+
+        let entry = ChainEntry {
+            sequence,
+            previous,
+            author: self.author.clone(),
+            method: method.to_string(),
+            params,
+            timestamp,
+            hash,
+        };
+
+        self.entries.push(entry.clone());
+        entry
+    }
+
+    /// Walks the log confirming each entry's `previous` matches the recomputed
+    /// hash of its predecessor and that sequences are contiguous.
+    pub fn verify(&self) -> Result<(), ChainError> {
+        let mut expected_previous: Option<String> = None;
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            let expected_sequence = (i as u64) + 1;
+            if entry.sequence != expected_sequence {
+                return Err(ChainError::SequenceGap { expected: expected_sequence, found: entry.sequence });
+            }
+
+            if entry.previous != expected_previous {
+                return Err(ChainError::HashMismatch { sequence: entry.sequence });
+            }
+
+            let recomputed = ChainEntry::compute_hash(entry.sequence, &entry.previous, &entry.author, &entry.method, &entry.params, &entry.timestamp)
+                .map_err(|_| ChainError::HashMismatch { sequence: entry.sequence })?;
+            if recomputed != entry.hash {
+                return Err(ChainError::HashMismatch { sequence: entry.sequence });
+            }
+
+            expected_previous = Some(entry.hash.clone());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod chain_tests {
+    use super::*;
+
+    #[test]
+    fn verify_accepts_an_untampered_chain() {
+        let mut chain = MessageChain::new("did:aeon:agent1");
+        chain.append("a2g/intent", serde_json::json!({"tool": "read_file"}));
+        chain.append("a2g/report", serde_json::json!({"status": "SUCCESS"}));
+        assert_eq!(chain.verify(), Ok(()));
+    }
+
+    #[test]
+    fn verify_rejects_a_tampered_entry() {
+        let mut chain = MessageChain::new("did:aeon:agent1");
+        chain.append("a2g/intent", serde_json::json!({"tool": "read_file"}));
+        chain.append("a2g/report", serde_json::json!({"status": "SUCCESS"}));
+
+        chain.entries[0].params = serde_json::json!({"tool": "delete_file"});
+
+        assert_eq!(chain.verify(), Err(ChainError::HashMismatch { sequence: 1 }));
+    }
+
+    #[test]
+    fn verify_rejects_a_reordered_chain() {
+        let mut chain = MessageChain::new("did:aeon:agent1");
+        chain.append("a2g/intent", serde_json::json!({"tool": "read_file"}));
+        chain.append("a2g/report", serde_json::json!({"status": "SUCCESS"}));
+
+        chain.entries.swap(0, 1);
+
+        assert_eq!(chain.verify(), Err(ChainError::SequenceGap { expected: 1, found: 2 }));
+    }
+}
@@ -0,0 +1,111 @@
+//! TEE remote-attestation evidence carried on `A2gRegister` so governance can
+//! require proof that an agent runs inside a genuine enclave before approving
+//! `capabilities_requested`. Modeled on the Open Enclave / SGX-quote attestation
+//! flows: a report plus optional runtime/init-time data conduits bound to a
+//! claimed SHA-256 hash.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use hex;
+
+use super::PolicyCapabilities;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AttestationFormat {
+    OpenEnclave,
+    SgxQuote,
+}
+
+/// A raw data blob plus the SHA-256 the agent claims it binds to (e.g. the
+/// public key it wants attested).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataConduit {
+    /// Base64-encoded raw bytes.
+    pub data: String,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttestationEvidence {
+    pub format: AttestationFormat,
+    /// Base64-encoded enclave quote/report.
+    pub report: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_data: Option<DataConduit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub init_time_data: Option<DataConduit>,
+    /// Policy capabilities the agent wants this attestation evaluated against, mirrored
+    /// by `A2gSimulate`'s dry-run `draft_policy`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft_policy: Option<PolicyCapabilities>,
+}
+
+/// Measured claims a verifier extracts from an [`AttestationEvidence`] report.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AttestationClaims {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrenclave: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrsigner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_runtime_data_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttestError {
+    UnsupportedFormat,
+    InvalidReport,
+    RuntimeDataMismatch,
+}
+
+impl std::fmt::Display for AttestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttestError::UnsupportedFormat => write!(f, "unsupported attestation format"),
+            AttestError::InvalidReport => write!(f, "enclave report failed validation"),
+            AttestError::RuntimeDataMismatch => write!(f, "runtime data is missing or does not match its claimed hash"),
+        }
+    }
+}
+
+impl std::error::Error for AttestError {}
+
+/// Validates an [`AttestationEvidence`] report and returns the claims it
+/// measures. Real implementations check the quote's signature chain against a
+/// TEE vendor's root of trust; this trait just defines the wire contract.
+pub trait AttestationVerifier {
+    fn verify(&self, evidence: &AttestationEvidence) -> Result<AttestationClaims, AttestError>;
+}
+
+/// No-op verifier that accepts any report and echoes back the claimed
+/// `runtime_data` hash unexamined. Ships as the default until a real quote
+/// validator is integrated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopAttestationVerifier;
+
+impl AttestationVerifier for NoopAttestationVerifier {
+    fn verify(&self, evidence: &AttestationEvidence) -> Result<AttestationClaims, AttestError> {
+        Ok(AttestationClaims {
+            mrenclave: None,
+            mrsigner: None,
+            verified_runtime_data_hash: evidence.runtime_data.as_ref().map(|c| c.sha256.clone()),
+        })
+    }
+}
+
+/// Binds an attestation's verified runtime-data hash to `public_key`. Returns
+/// the combined binding hash the register flow should store alongside the
+/// agent's capability grant.
+pub fn bind_runtime_data(claims: &AttestationClaims, public_key: &str) -> Result<String, AttestError> {
+    let runtime_hash = claims
+        .verified_runtime_data_hash
+        .as_ref()
+        .ok_or(AttestError::RuntimeDataMismatch)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(runtime_hash.as_bytes());
+    hasher.update(b":");
+    hasher.update(public_key.as_bytes());
+    Ok(hex::encode(hasher.finalize()))
+}
@@ -0,0 +1,129 @@
+//! Short-lived session tokens so a registered agent doesn't have to sign every
+//! `A2gIntent` with per-message HMAC/Ed25519 — the initial-connection-then-token
+//! pattern applied to governance. `IntentContext::session_token` lets an agent
+//! present a token minted at register time instead of a fresh `signature`.
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use hex;
+
+use serde::{Deserialize, Serialize};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A short-lived credential governance hands back on register (or alongside a
+/// verdict) so the agent can skip signing subsequent intents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionToken {
+    /// Opaque `payload_hex.mac_hex` string; present this verbatim as
+    /// `IntentContext::session_token`.
+    pub token: String,
+    pub agent_did: String,
+    pub issued_at: i64,
+    pub expires_at: i64,
+    pub scope: Vec<String>,
+}
+
+/// The fields embedded in a [`SessionToken::token`], hex-encoded as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TokenPayload {
+    issued_at: i64,
+    expires_at: i64,
+    scope: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenError {
+    Malformed,
+    InvalidMac,
+    Expired,
+    InsufficientScope,
+}
+
+impl std::fmt::Display for TokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenError::Malformed => write!(f, "malformed session token"),
+            TokenError::InvalidMac => write!(f, "session token failed MAC verification"),
+            TokenError::Expired => write!(f, "session token has expired"),
+            TokenError::InsufficientScope => write!(f, "session token does not cover the required scope"),
+        }
+    }
+}
+
+impl std::error::Error for TokenError {}
+
+/// Mints and validates [`SessionToken`]s on governance's behalf using an HMAC
+/// with a server secret over `agent_did:payload_hex`, where `payload_hex` is
+/// the hex-encoded JSON of the token's `issued_at`, `expires_at` and `scope`.
+pub struct TokenIssuer;
+
+impl TokenIssuer {
+    pub fn issue(server_secret: &str, agent_did: &str, scope: Vec<String>, ttl_ms: i64) -> SessionToken {
+        let issued_at = Utc::now().timestamp_millis();
+        let expires_at = issued_at + ttl_ms;
+        let token = Self::encode(server_secret, agent_did, issued_at, expires_at, &scope);
+
+        SessionToken { token, agent_did: agent_did.to_string(), issued_at, expires_at, scope }
+    }
+
+    /// Verifies `token` was minted by this issuer for `agent_did` and that
+    /// `required_scope` is covered, using a constant-time MAC comparison.
+    pub fn validate(server_secret: &str, token: &str, agent_did: &str, required_scope: &str) -> Result<(), TokenError> {
+        let (issued_at, expires_at, scope, mac_hex) = Self::decode(token)?;
+
+        let expected = Self::encode(server_secret, agent_did, issued_at, expires_at, &scope);
+        let (_, _, _, expected_mac_hex) = Self::decode(&expected)?;
+
+        let actual_bytes = hex::decode(&mac_hex).map_err(|_| TokenError::Malformed)?;
+        let expected_bytes = hex::decode(&expected_mac_hex).map_err(|_| TokenError::Malformed)?;
+        if !bool::from(actual_bytes.ct_eq(&expected_bytes)) {
+            return Err(TokenError::InvalidMac);
+        }
+
+        if Utc::now().timestamp_millis() > expires_at {
+            return Err(TokenError::Expired);
+        }
+
+        if !scope.iter().any(|s| s == required_scope) {
+            return Err(TokenError::InsufficientScope);
+        }
+
+        Ok(())
+    }
+
+    /// Validates `token` and, if still valid, mints a fresh one for the same
+    /// agent and scope with an extended expiry.
+    pub fn refresh(server_secret: &str, token: &str, agent_did: &str, ttl_ms: i64) -> Result<SessionToken, TokenError> {
+        let (_, _, scope, _) = Self::decode(token)?;
+        // Any non-empty scope entry is enough to prove the caller holds a live token.
+        let required_scope = scope.first().cloned().unwrap_or_default();
+        Self::validate(server_secret, token, agent_did, &required_scope)?;
+
+        Ok(Self::issue(server_secret, agent_did, scope, ttl_ms))
+    }
+
+    fn encode(server_secret: &str, agent_did: &str, issued_at: i64, expires_at: i64, scope: &[String]) -> String {
+        let payload = TokenPayload { issued_at, expires_at, scope: scope.to_vec() };
+        let payload_json = serde_json::to_string(&payload).expect("TokenPayload always serializes");
+        let payload_hex = hex::encode(payload_json.as_bytes());
+
+        let mac_input = format!("{}:{}", agent_did, payload_hex);
+        let mut mac = HmacSha256::new_from_slice(server_secret.as_bytes()).expect("HMAC accepts any key length");
+        mac.update(mac_input.as_bytes());
+        let mac_hex = hex::encode(mac.finalize().into_bytes());
+
+        format!("{}.{}", payload_hex, mac_hex)
+    }
+
+    fn decode(token: &str) -> Result<(i64, i64, Vec<String>, String), TokenError> {
+        let (payload_hex, mac_hex) = token.split_once('.').ok_or(TokenError::Malformed)?;
+
+        let payload_json = hex::decode(payload_hex).map_err(|_| TokenError::Malformed)?;
+        let payload: TokenPayload = serde_json::from_slice(&payload_json).map_err(|_| TokenError::Malformed)?;
+
+        Ok((payload.issued_at, payload.expires_at, payload.scope, mac_hex.to_string()))
+    }
+}